@@ -14,6 +14,7 @@
 #include <boost/algorithm/string/join.hpp>
 #include <boost/format.hpp>
 #include "ametsuchi/impl/executor_common.hpp"
+#include "ametsuchi/impl/postgres_setting_query.hpp"
 #include "ametsuchi/impl/soci_std_optional.hpp"
 #include "ametsuchi/impl/soci_utils.hpp"
 #include "interfaces/commands/add_asset_quantity.hpp"
@@ -204,6 +205,24 @@ namespace {
         account_id);
   }
 
+  /**
+   * Generate an SQL subquery which checks if the count returned by the given
+   * query has reached the limit stored in the ledger setting with the given
+   * key. Absent or non-numeric setting means there is no limit
+   */
+  std::string checkSettingLimitReached(
+      const std::string &count_query,
+      const shared_model::interface::types::SettingKeyType &setting_key) {
+    return (boost::format(R"(
+          SELECT COALESCE(
+              (%1%) >= (SELECT setting_value::numeric FROM setting
+                        WHERE setting_key = '%2%'
+                          AND setting_value ~ '^[0-9]+$'),
+              false))")
+            % count_query % setting_key)
+        .str();
+  }
+
   std::string checkAccountGrantablePermission(
       Grantable permission,
       const shared_model::interface::types::AccountIdType &creator_id,
@@ -815,13 +834,23 @@ namespace iroha {
                 FROM account_permissions AS ap, domain_role_permissions_bits AS dpb
            ),
            has_perm AS (%2%),
+           has_root_perm AS (%3%),
+           accounts_limit_reached AS (%4%),
           )") % kRolePermissionSetSize
-                % checkAccountRolePermission(Role::kCreateAccount, ":creator"))
+                % checkAccountRolePermission(Role::kCreateAccount, ":creator")
+                % checkAccountRolePermission(Role::kRoot, ":creator")
+                % checkSettingLimitReached(
+                      "SELECT count(*) FROM account WHERE domain_id = :domain",
+                      kMaxAccountsPerDomainKey))
                    .str(),
                R"(AND (SELECT * FROM has_perm)
-                AND (SELECT * FROM creator_has_enough_permissions))",
+                AND (SELECT * FROM creator_has_enough_permissions)
+                AND ((SELECT * FROM has_root_perm)
+                     OR NOT (SELECT * FROM accounts_limit_reached)))",
                R"(WHEN NOT (SELECT * FROM has_perm) THEN 2
-                WHEN NOT (SELECT * FROM creator_has_enough_permissions) THEN 2)"});
+                WHEN NOT (SELECT * FROM creator_has_enough_permissions) THEN 2
+                WHEN NOT (SELECT * FROM has_root_perm)
+                     AND (SELECT * FROM accounts_limit_reached) THEN 5)"});
 
       create_asset_statements_ = makeCommandStatements(
           sql_,
@@ -841,11 +870,21 @@ namespace iroha {
             ELSE 1
           END AS result)",
           {(boost::format(R"(
-              has_perm AS (%s),)")
-            % checkAccountRolePermission(Role::kCreateAsset, ":creator"))
+              has_perm AS (%s),
+              has_root_perm AS (%s),
+              assets_limit_reached AS (%s),)")
+            % checkAccountRolePermission(Role::kCreateAsset, ":creator")
+            % checkAccountRolePermission(Role::kRoot, ":creator")
+            % checkSettingLimitReached(
+                  "SELECT count(*) FROM asset WHERE domain_id = :domain",
+                  kMaxAssetsPerDomainKey))
                .str(),
-           R"(WHERE (SELECT * FROM has_perm))",
-           R"(WHEN NOT (SELECT * FROM has_perm) THEN 2)"});
+           R"(WHERE (SELECT * FROM has_perm)
+                AND ((SELECT * FROM has_root_perm)
+                     OR NOT (SELECT * FROM assets_limit_reached)))",
+           R"(WHEN NOT (SELECT * FROM has_perm) THEN 2
+            WHEN NOT (SELECT * FROM has_root_perm)
+                 AND (SELECT * FROM assets_limit_reached) THEN 5)"});
 
       create_domain_statements_ = makeCommandStatements(
           sql_,
@@ -865,11 +904,20 @@ namespace iroha {
             ELSE 1
           END AS result)",
           {(boost::format(R"(
-              has_perm AS (%s),)")
-            % checkAccountRolePermission(Role::kCreateDomain, ":creator"))
+              has_perm AS (%s),
+              has_root_perm AS (%s),
+              domains_limit_reached AS (%s),)")
+            % checkAccountRolePermission(Role::kCreateDomain, ":creator")
+            % checkAccountRolePermission(Role::kRoot, ":creator")
+            % checkSettingLimitReached("SELECT count(*) FROM domain",
+                                       kMaxDomainsKey))
                .str(),
-           R"(WHERE (SELECT * FROM has_perm))",
-           R"(WHEN NOT (SELECT * FROM has_perm) THEN 2)"});
+           R"(WHERE (SELECT * FROM has_perm)
+                AND ((SELECT * FROM has_root_perm)
+                     OR NOT (SELECT * FROM domains_limit_reached)))",
+           R"(WHEN NOT (SELECT * FROM has_perm) THEN 2
+            WHEN NOT (SELECT * FROM has_root_perm)
+                 AND (SELECT * FROM domains_limit_reached) THEN 5)"});
 
       create_role_statements_ = makeCommandStatements(
           sql_,
@@ -57,6 +57,14 @@ PostgresSettingQuery::update(
 
   try {
     get_and_log(kMaxDescriptionSizeKey, base->max_description_size);
+    get_and_log(kMaxSignaturesPerTransactionKey,
+                base->max_signatures_per_transaction);
+    get_and_log(kMaxCommandsPerTransactionKey,
+                base->max_commands_per_transaction);
+    get_and_log(kMaxTransactionSizeKey, base->max_transaction_size);
+    get_and_log(kMaxDomainsKey, base->max_domains);
+    get_and_log(kMaxAccountsPerDomainKey, base->max_accounts_per_domain);
+    get_and_log(kMaxAssetsPerDomainKey, base->max_assets_per_domain);
   } catch (std::exception &e) {
     return expected::makeError(e.what());
   }
@@ -66,3 +74,17 @@ PostgresSettingQuery::update(
 
 const shared_model::interface::types::SettingKeyType
     iroha::ametsuchi::kMaxDescriptionSizeKey = "MaxDescriptionSize";
+const shared_model::interface::types::SettingKeyType
+    iroha::ametsuchi::kMaxSignaturesPerTransactionKey =
+        "MaxSignaturesPerTransaction";
+const shared_model::interface::types::SettingKeyType
+    iroha::ametsuchi::kMaxCommandsPerTransactionKey =
+        "MaxCommandsPerTransaction";
+const shared_model::interface::types::SettingKeyType
+    iroha::ametsuchi::kMaxTransactionSizeKey = "MaxTransactionSize";
+const shared_model::interface::types::SettingKeyType
+    iroha::ametsuchi::kMaxDomainsKey = "MaxDomains";
+const shared_model::interface::types::SettingKeyType
+    iroha::ametsuchi::kMaxAccountsPerDomainKey = "MaxAccountsPerDomain";
+const shared_model::interface::types::SettingKeyType
+    iroha::ametsuchi::kMaxAssetsPerDomainKey = "MaxAssetsPerDomain";
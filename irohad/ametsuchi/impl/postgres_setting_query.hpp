@@ -42,6 +42,17 @@ namespace iroha {
 
     extern const shared_model::interface::types::SettingKeyType
         kMaxDescriptionSizeKey;
+    extern const shared_model::interface::types::SettingKeyType
+        kMaxSignaturesPerTransactionKey;
+    extern const shared_model::interface::types::SettingKeyType
+        kMaxCommandsPerTransactionKey;
+    extern const shared_model::interface::types::SettingKeyType
+        kMaxTransactionSizeKey;
+    extern const shared_model::interface::types::SettingKeyType kMaxDomainsKey;
+    extern const shared_model::interface::types::SettingKeyType
+        kMaxAccountsPerDomainKey;
+    extern const shared_model::interface::types::SettingKeyType
+        kMaxAssetsPerDomainKey;
   }  // namespace ametsuchi
 }  // namespace iroha
 
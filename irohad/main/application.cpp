@@ -108,11 +108,13 @@ Irohad::Irohad(
     const boost::optional<GossipPropagationStrategyParams>
         &opt_mst_gossip_params,
     const boost::optional<iroha::torii::TlsParams> &torii_tls_params,
+    int torii_max_message_size,
     boost::optional<IrohadConfig::InterPeerTls> inter_peer_tls_config)
     : block_store_dir_(block_store_dir),
       listen_ip_(listen_ip),
       torii_port_(torii_port),
       torii_tls_params_(torii_tls_params),
+      torii_max_message_size_(torii_max_message_size),
       internal_port_(internal_port),
       max_proposal_size_(max_proposal_size),
       proposal_delay_(proposal_delay),
@@ -937,7 +939,9 @@ Irohad::RunResult Irohad::run() {
   torii_server = std::make_unique<ServerRunner>(
       listen_ip_ + ":" + std::to_string(torii_port_),
       log_manager_->getChild("ToriiServerRunner")->getLogger(),
-      false);
+      false,
+      boost::none,
+      torii_max_message_size_);
 
   // Initializing internal server
   internal_server = std::make_unique<ServerRunner>(
@@ -965,7 +969,8 @@ Irohad::RunResult Irohad::run() {
           listen_ip_ + ":" + std::to_string(torii_tls_params_->port),
           log_manager_->getChild("ToriiTlsServerRunner")->getLogger(),
           false,
-          tls_creds);
+          tls_creds,
+          torii_max_message_size_);
       return (*torii_tls_server)
                  ->append(command_service_transport)
                  .append(query_service)
@@ -108,6 +108,8 @@ class Irohad {
    * TODO mboldyrev 03.11.2018 IR-1844 Refactor the constructor.
    * @param torii_tls_params - optional TLS params for torii.
    * @see iroha::torii::TlsParams
+   * @param torii_max_message_size - maximum size of a message received by
+   * torii, in bytes
    * @param inter_peer_tls_config - set up TLS in peer-to-peer communication
    */
   Irohad(const boost::optional<std::string> &block_store_dir,
@@ -129,6 +131,7 @@ class Irohad {
              &opt_mst_gossip_params = boost::none,
          const boost::optional<iroha::torii::TlsParams> &torii_tls_params =
              boost::none,
+         int torii_max_message_size = std::numeric_limits<int>::max(),
          boost::optional<IrohadConfig::InterPeerTls> inter_peer_tls_config =
              boost::none);
 
@@ -220,6 +223,7 @@ class Irohad {
   const std::string listen_ip_;
   size_t torii_port_;
   boost::optional<iroha::torii::TlsParams> torii_tls_params_;
+  int torii_max_message_size_;
   size_t internal_port_;
   size_t max_proposal_size_;
   std::chrono::milliseconds proposal_delay_;
@@ -9,6 +9,7 @@ namespace config_members {
   const char *BlockStorePath = "block_store_path";
   const char *ToriiPort = "torii_port";
   const char *ToriiTlsParams = "torii_tls_params";
+  const char *ToriiMaxMessageSize = "torii_max_message_size";
   const char *InterPeerTls = "inter_peer_tls";
   const char *PeerCertProvider = "peer_certificates";
   const char *RootCert = "root_certificate";
@@ -15,6 +15,7 @@ namespace config_members {
   extern const char *BlockStorePath;
   extern const char *ToriiPort;
   extern const char *ToriiTlsParams;
+  extern const char *ToriiMaxMessageSize;
   extern const char *InterPeerTls;
   extern const char *PeerCertProvider;
   extern const char *RootCert;
@@ -5,6 +5,7 @@
 
 #include <csignal>
 #include <fstream>
+#include <limits>
 #include <thread>
 
 #include <gflags/gflags.h>
@@ -30,6 +31,8 @@ static const std::string kLogSettingsFromConfigFile = "config_file";
 static const uint32_t kMstExpirationTimeDefault = 1440;
 static const uint32_t kMaxRoundsDelayDefault = 3000;
 static const uint32_t kStaleStreamMaxRoundsDefault = 2;
+static const int32_t kToriiMaxMessageSizeDefault =
+    std::numeric_limits<int32_t>::max();
 static const std::string kDefaultWorkingDatabaseName{"iroha_default"};
 
 /**
@@ -223,7 +226,8 @@ int main(int argc, char *argv[]) {
       log_manager->getChild("Irohad"),
       boost::make_optional(config.mst_support,
                            iroha::GossipPropagationStrategyParams{}),
-      config.torii_tls_params);
+      config.torii_tls_params,
+      config.torii_max_message_size.value_or(kToriiMaxMessageSizeDefault));
 
   // Check if iroha daemon storage was successfully initialized
   if (not irohad.storage) {
@@ -40,11 +40,13 @@ ServerRunner::ServerRunner(
     const std::string &address,
     logger::LoggerPtr log,
     bool reuse,
-    const boost::optional<std::shared_ptr<const TlsCredentials>> &my_tls_creds)
+    const boost::optional<std::shared_ptr<const TlsCredentials>> &my_tls_creds,
+    int max_receive_message_size)
     : log_(std::move(log)),
       server_address_(address),
       credentials_(createCredentials(my_tls_creds)),
-      reuse_(reuse) {}
+      reuse_(reuse),
+      max_receive_message_size_(max_receive_message_size) {}
 
 ServerRunner::~ServerRunner() {
   shutdown(std::chrono::system_clock::now());
@@ -70,7 +72,7 @@ iroha::expected::Result<int, std::string> ServerRunner::run() {
   }
 
   // in order to bypass built-it limitation of gRPC message size
-  builder.SetMaxReceiveMessageSize(INT_MAX);
+  builder.SetMaxReceiveMessageSize(max_receive_message_size_);
   builder.SetMaxSendMessageSize(INT_MAX);
 
   // enable retry policy
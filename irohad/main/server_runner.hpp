@@ -6,6 +6,8 @@
 #ifndef MAIN_SERVER_RUNNER_HPP
 #define MAIN_SERVER_RUNNER_HPP
 
+#include <limits>
+
 #include <grpc++/grpc++.h>
 #include <grpc++/impl/codegen/service_type.h>
 #include "common/result.hpp"
@@ -26,13 +28,16 @@ namespace iroha {
        * @param log to print progress to
        * @param reuse - allow multiple sockets to bind to the same port
        * @param my_tls_creds - TLS credentials_ for this server, if required
+       * @param max_receive_message_size - maximum size of a received message,
+       * in bytes
        */
       explicit ServerRunner(
           const std::string &address,
           logger::LoggerPtr log,
           bool reuse = true,
           const boost::optional<std::shared_ptr<const TlsCredentials>>
-              &my_tls_creds = boost::none);
+              &my_tls_creds = boost::none,
+          int max_receive_message_size = std::numeric_limits<int>::max());
 
       ~ServerRunner();
 
@@ -74,6 +79,7 @@ namespace iroha {
       std::string server_address_;
       std::shared_ptr<grpc::ServerCredentials> credentials_;
       bool reuse_;
+      int max_receive_message_size_;
       std::vector<std::shared_ptr<grpc::Service>> services_;
     };
 
@@ -168,6 +168,14 @@ namespace shared_model {
       return impl_->meta_;
     }
 
+    size_t Transaction::rawSignaturesCount() const {
+      return impl_->proto_->signatures_size();
+    }
+
+    size_t Transaction::serializedSize() const {
+      return impl_->proto_->ByteSizeLong();
+    }
+
     Transaction::ModelType *Transaction::clone() const {
       return new Transaction(TransportType(*impl_->proto_));
     }
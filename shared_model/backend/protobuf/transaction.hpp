@@ -56,6 +56,10 @@ namespace shared_model {
       std::optional<std::shared_ptr<interface::BatchMeta>> batchMeta()
           const override;
 
+      size_t rawSignaturesCount() const override;
+
+      size_t serializedSize() const override;
+
      protected:
       Transaction::ModelType *clone() const override;
 
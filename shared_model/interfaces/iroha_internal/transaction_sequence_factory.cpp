@@ -58,27 +58,38 @@ namespace shared_model {
       for (const auto &tx : transactions | boost::adaptors::indexed(1)) {
         validation::ValidationErrorCreator tx_error_creator;
         // perform stateless validation checks
-        // check signatures validness
-        if (not boost::empty(tx.value()->signatures())) {
-          tx_error_creator |= field_validator.validateSignatures(
-              tx.value()->signatures(), tx.value()->payload());
-        }
-        // check transaction validness
-        tx_error_creator |= transaction_validator.validate(*tx.value());
-
-        // if transaction is valid, try to form batch out of it
-        if (auto meta = tx.value()->batchMeta()) {
-          auto hashes = meta.value()->reducedHashes();
-          auto batch_hash =
-              TransactionBatchHelpers::calculateReducedBatchHash(hashes);
-          extracted_batches[batch_hash].push_back(tx.value());
+        // reject oversized and over-signed transactions before verifying any
+        // signature
+        if (auto error = validation::aggregateErrors(
+                "Transaction limits",
+                {},
+                {field_validator.validateTransactionSize(*tx.value()),
+                 field_validator.validateCommandsCount(*tx.value()),
+                 field_validator.validateSignaturesCount(*tx.value())})) {
+          tx_error_creator |= std::move(error);
         } else {
-          batch_factory->createTransactionBatch(tx.value())
-              .match(insert_batch, [&tx_error_creator](const auto &err) {
-                tx_error_creator.addReason(fmt::format(
-                    "Could not create transaction batch from this tx: {}.",
-                    err.error));
-              });
+          // check signatures validness
+          if (not boost::empty(tx.value()->signatures())) {
+            tx_error_creator |= field_validator.validateSignatures(
+                tx.value()->signatures(), tx.value()->payload());
+          }
+          // check transaction validness
+          tx_error_creator |= transaction_validator.validate(*tx.value());
+
+          // if transaction is valid, try to form batch out of it
+          if (auto meta = tx.value()->batchMeta()) {
+            auto hashes = meta.value()->reducedHashes();
+            auto batch_hash =
+                TransactionBatchHelpers::calculateReducedBatchHash(hashes);
+            extracted_batches[batch_hash].push_back(tx.value());
+          } else {
+            batch_factory->createTransactionBatch(tx.value())
+                .match(insert_batch, [&tx_error_creator](const auto &err) {
+                  tx_error_creator.addReason(fmt::format(
+                      "Could not create transaction batch from this tx: {}.",
+                      err.error));
+                });
+          }
         }
 
         error_creator |=
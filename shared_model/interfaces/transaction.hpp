@@ -59,6 +59,17 @@ namespace shared_model {
        */
       virtual std::optional<std::shared_ptr<BatchMeta>> batchMeta() const = 0;
 
+      /**
+       * @return number of signatures as they were received, including the
+       * ones with repeated public keys which are merged in signatures()
+       */
+      virtual size_t rawSignaturesCount() const = 0;
+
+      /**
+       * @return size of the transaction as it was received, in bytes
+       */
+      virtual size_t serializedSize() const = 0;
+
       std::string toString() const override;
     };
 
@@ -7,11 +7,14 @@
 
 #include <limits>
 #include <string_view>
+#include <unordered_set>
 
 #include <fmt/core.h>
+#include <boost/algorithm/string/case_conv.hpp>
 #include <boost/algorithm/string_regex.hpp>
 #include <boost/format.hpp>
 #include <boost/range/adaptor/indexed.hpp>
+#include <boost/range/size.hpp>
 #include "common/bind.hpp"
 #include "cryptography/crypto_provider/crypto_verifier.hpp"
 #include "interfaces/common_objects/account.hpp"
@@ -25,6 +28,7 @@
 #include "interfaces/queries/asset_pagination_meta.hpp"
 #include "interfaces/queries/query_payload_meta.hpp"
 #include "interfaces/queries/tx_pagination_meta.hpp"
+#include "interfaces/transaction.hpp"
 #include "multihash/multihash.hpp"
 #include "validators/field_validator.hpp"
 #include "validators/validation_error_helpers.hpp"
@@ -126,7 +130,12 @@ namespace shared_model {
                                    TimeFunction time_provider)
         : future_gap_(future_gap),
           time_provider_(time_provider),
-          max_description_size(config->settings->max_description_size) {}
+          max_description_size(config->settings->max_description_size),
+          max_signatures_per_transaction(
+              config->settings->max_signatures_per_transaction),
+          max_commands_per_transaction(
+              config->settings->max_commands_per_transaction),
+          max_transaction_size(config->settings->max_transaction_size) {}
 
     std::optional<ValidationError> FieldValidator::validateAccountId(
         const interface::types::AccountIdType &account_id) const {
@@ -351,6 +360,56 @@ namespace shared_model {
       return std::move(error_creator).getValidationError("Signatures list");
     }
 
+    std::optional<ValidationError> FieldValidator::validateSignaturesCount(
+        const interface::Transaction &transaction) const {
+      ValidationErrorCreator error_creator;
+      const auto signatures_count = transaction.rawSignaturesCount();
+      if (signatures_count > max_signatures_per_transaction) {
+        error_creator.addReason(
+            fmt::format("Contains {} signatures, maximum is '{}'.",
+                        signatures_count,
+                        max_signatures_per_transaction));
+      } else {
+        // public keys are hex strings of any case, so compare them lowercased
+        std::unordered_set<std::string> public_keys;
+        for (const auto &signature : transaction.signatures()) {
+          public_keys.insert(
+              boost::algorithm::to_lower_copy(signature.publicKey()));
+        }
+        if (signatures_count != public_keys.size()) {
+          error_creator.addReason(
+              "Contains several signatures with the same public key.");
+        }
+      }
+      return std::move(error_creator).getValidationError("Signatures list");
+    }
+
+    std::optional<ValidationError> FieldValidator::validateCommandsCount(
+        const interface::Transaction &transaction) const {
+      const auto commands_count = boost::size(transaction.commands());
+      if (commands_count > max_commands_per_transaction) {
+        return ValidationError(
+            "Commands list",
+            {fmt::format("Contains {} commands, maximum is '{}'.",
+                         commands_count,
+                         max_commands_per_transaction)});
+      }
+      return std::nullopt;
+    }
+
+    std::optional<ValidationError> FieldValidator::validateTransactionSize(
+        const interface::Transaction &transaction) const {
+      const auto size = transaction.serializedSize();
+      if (size > max_transaction_size) {
+        return ValidationError(
+            "Transaction size",
+            {fmt::format("Transaction exceeds {} bytes, its size is {} bytes.",
+                         max_transaction_size,
+                         size)});
+      }
+      return std::nullopt;
+    }
+
     std::optional<ValidationError> FieldValidator::validateQueryPayloadMeta(
         const interface::QueryPayloadMeta &meta) const {
       return std::nullopt;
@@ -29,6 +29,7 @@ namespace shared_model {
     class BatchMeta;
     class Domain;
     class Peer;
+    class Transaction;
     class TxPaginationMeta;
   }  // namespace interface
 
@@ -161,6 +162,26 @@ namespace shared_model {
           const interface::types::SignatureRangeType &signatures,
           const crypto::Blob &source) const;
 
+      /**
+       * Validate number of received signatures against
+       * max_signatures_per_transaction and reject repeated public keys. Does
+       * not verify the signatures themselves
+       */
+      std::optional<ValidationError> validateSignaturesCount(
+          const interface::Transaction &transaction) const;
+
+      /**
+       * Validate number of commands against max_commands_per_transaction
+       */
+      std::optional<ValidationError> validateCommandsCount(
+          const interface::Transaction &transaction) const;
+
+      /**
+       * Validate serialized size of transaction against max_transaction_size
+       */
+      std::optional<ValidationError> validateTransactionSize(
+          const interface::Transaction &transaction) const;
+
       std::optional<ValidationError> validateQueryPayloadMeta(
           const interface::QueryPayloadMeta &meta) const;
 
@@ -213,6 +234,9 @@ namespace shared_model {
       /// limit for the set account detail size in bytes
       static constexpr size_t value_size = 4 * 1024 * 1024;
       size_t max_description_size;
+      size_t max_signatures_per_transaction;
+      size_t max_commands_per_transaction;
+      size_t max_transaction_size;
     };
 
     std::optional<ValidationError> validatePubkey(
@@ -12,6 +12,14 @@ namespace shared_model {
     std::unique_ptr<shared_model::validation::Settings> getDefaultSettings() {
       shared_model::validation::Settings default_settings{};
       default_settings.max_description_size = kDefaultDescriptionSize;
+      default_settings.max_signatures_per_transaction =
+          kDefaultMaxSignaturesPerTransaction;
+      default_settings.max_commands_per_transaction =
+          kDefaultMaxCommandsPerTransaction;
+      default_settings.max_transaction_size = kDefaultMaxTransactionSize;
+      default_settings.max_domains = kDefaultMaxDomains;
+      default_settings.max_accounts_per_domain = kDefaultMaxAccountsPerDomain;
+      default_settings.max_assets_per_domain = kDefaultMaxAssetsPerDomain;
       return std::make_unique<shared_model::validation::Settings>(
           std::move(default_settings));
     }
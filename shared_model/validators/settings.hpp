@@ -6,6 +6,8 @@
 #ifndef IROHA_SHARED_MODEL_SETTINGS_HPP
 #define IROHA_SHARED_MODEL_SETTINGS_HPP
 
+#include <limits>
+
 #include "interfaces/common_objects/types.hpp"
 
 namespace shared_model {
@@ -17,9 +19,26 @@ namespace shared_model {
      */
     struct Settings {
       size_t max_description_size;
+      size_t max_signatures_per_transaction;
+      size_t max_commands_per_transaction;
+      size_t max_transaction_size;
+      size_t max_domains;
+      size_t max_accounts_per_domain;
+      size_t max_assets_per_domain;
     };
 
     const size_t kDefaultDescriptionSize = 64;
+    /// matches the upper bound of account quorum
+    const size_t kDefaultMaxSignaturesPerTransaction = 128;
+    const size_t kDefaultMaxCommandsPerTransaction = 10000;
+    /// matches the default gRPC receive message size limit of Torii
+    const size_t kDefaultMaxTransactionSize = std::numeric_limits<int>::max();
+    /// entity limits are not enforced unless set in the ledger
+    const size_t kDefaultMaxDomains = std::numeric_limits<size_t>::max();
+    const size_t kDefaultMaxAccountsPerDomain =
+        std::numeric_limits<size_t>::max();
+    const size_t kDefaultMaxAssetsPerDomain =
+        std::numeric_limits<size_t>::max();
 
     std::unique_ptr<shared_model::validation::Settings> getDefaultSettings();
 
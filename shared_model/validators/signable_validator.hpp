@@ -6,9 +6,15 @@
 #ifndef IROHA_SHARED_MODEL_SIGNABLE_VALIDATOR_HPP
 #define IROHA_SHARED_MODEL_SIGNABLE_VALIDATOR_HPP
 
+#include <type_traits>
+
 #include "validators/validation_error_helpers.hpp"
 
 namespace shared_model {
+  namespace interface {
+    class Transaction;
+  }
+
   namespace validation {
 
     template <typename ModelValidator,
@@ -22,6 +28,21 @@ namespace shared_model {
                                                   Validator &&validator) const {
         ValidationErrorCreator error_creator;
 
+        if constexpr (std::is_same_v<std::decay_t<Model>,
+                                     interface::Transaction>) {
+          // reject oversized and over-signed transactions before verifying
+          // any signature
+          if (auto error = aggregateErrors(
+                  "Transaction limits",
+                  {},
+                  {field_validator_.validateTransactionSize(model),
+                   field_validator_.validateCommandsCount(model),
+                   field_validator_.validateSignaturesCount(model)})) {
+            error_creator |= std::move(error);
+            return std::move(error_creator).getValidationError("SignedData");
+          }
+        }
+
         error_creator |= std::forward<Validator>(validator)(model);
         if (SignatureRequired or not model.signatures().empty()) {
           error_creator |= field_validator_.validateSignatures(
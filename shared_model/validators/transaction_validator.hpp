@@ -289,6 +289,15 @@ namespace shared_model {
           error_creator.addReason(
               "Transaction must contain at least one command.");
         }
+        // reject oversized transactions before validating each command
+        if (auto error = field_validator_.validateTransactionSize(tx)) {
+          error_creator |= std::move(error);
+          return std::move(error_creator).getValidationError("Transaction");
+        }
+        if (auto error = field_validator_.validateCommandsCount(tx)) {
+          error_creator |= std::move(error);
+          return std::move(error_creator).getValidationError("Transaction");
+        }
 
         error_creator |=
             field_validator_.validateCreatorAccountId(tx.creatorAccountId());
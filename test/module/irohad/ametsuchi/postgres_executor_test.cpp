@@ -5,6 +5,7 @@
 
 #include "ametsuchi/impl/postgres_command_executor.hpp"
 #include "ametsuchi/impl/postgres_query_executor.hpp"
+#include "ametsuchi/impl/postgres_setting_query.hpp"
 #include "ametsuchi/impl/postgres_wsv_query.hpp"
 #include "backend/protobuf/proto_permission_to_string.hpp"
 #include "framework/common_constants.hpp"
@@ -173,6 +174,16 @@ namespace iroha {
                     true));
       }
 
+      /**
+       * Set a ledger setting and check command success
+       */
+      void setSetting(
+          const shared_model::interface::types::SettingKeyType &key,
+          const shared_model::interface::types::SettingValueType &value) {
+        CHECK_SUCCESSFUL_RESULT(execute(
+            *mock_command_factory->constructSetSettingValue(key, value), true));
+      }
+
       /*
        * The functions below create common objects with default parameters
        * without any validation - specifically for SetUp methods
@@ -715,6 +726,64 @@ namespace iroha {
       ASSERT_EQ(asset_id, asset.get()->assetId());
     }
 
+    /**
+     * @given command and a limit of assets per domain
+     * @when trying to create the last asset allowed in the domain
+     * @then asset is created
+     */
+    TEST_F(CreateAsset, AssetsCountAtLimit) {
+      role_permissions.set(
+          shared_model::interface::permissions::Role::kCreateAsset);
+      createDefaultRole();
+      createDefaultDomain();
+      createDefaultAccount();
+      setSetting(kMaxAssetsPerDomainKey, "1");
+      CHECK_SUCCESSFUL_RESULT(execute(
+          *mock_command_factory->constructCreateAsset("coin", domain_id, 1)));
+      auto asset = sql_query->getAsset(asset_id);
+      ASSERT_TRUE(asset);
+      ASSERT_EQ(asset_id, asset.get()->assetId());
+    }
+
+    /**
+     * @given command and a limit of assets per domain, which is reached
+     * @when trying to create one more asset in the domain
+     * @then asset is not created
+     */
+    TEST_F(CreateAsset, AssetsCountOverLimit) {
+      role_permissions.set(
+          shared_model::interface::permissions::Role::kCreateAsset);
+      createDefaultRole();
+      createDefaultDomain();
+      createDefaultAccount();
+      setSetting(kMaxAssetsPerDomainKey, "1");
+      addAsset("coin");
+      auto cmd_result = execute(
+          *mock_command_factory->constructCreateAsset("coin2", domain_id, 1));
+      ASSERT_FALSE(sql_query->getAsset("coin2#" + domain_id));
+
+      std::vector<std::string> query_args{"coin2", domain_id, "1"};
+      CHECK_ERROR_CODE_AND_MESSAGE(cmd_result, 5, query_args);
+    }
+
+    /**
+     * @given command, root permission and a limit of assets per domain, which
+     * is reached
+     * @when trying to create one more asset in the domain
+     * @then asset is created
+     */
+    TEST_F(CreateAsset, AssetsCountOverLimitWithRoot) {
+      role_permissions.set(shared_model::interface::permissions::Role::kRoot);
+      createDefaultRole();
+      createDefaultDomain();
+      createDefaultAccount();
+      setSetting(kMaxAssetsPerDomainKey, "1");
+      addAsset("coin");
+      CHECK_SUCCESSFUL_RESULT(execute(
+          *mock_command_factory->constructCreateAsset("coin2", domain_id, 1)));
+      ASSERT_TRUE(sql_query->getAsset("coin2#" + domain_id));
+    }
+
     class CreateDomain : public CommandExecutorTest {
      public:
       void SetUp() override {
@@ -801,6 +870,110 @@ namespace iroha {
       ASSERT_EQ(dom.get()->domainId(), domain2_id);
     }
 
+    /**
+     * @given command and a limit of domains
+     * @when trying to create the last domain allowed
+     * @then domain is created
+     */
+    TEST_F(CreateDomain, DomainsCountAtLimit) {
+      addAllPermsWithoutRoot();
+      setSetting(kMaxDomainsKey, "2");
+      CHECK_SUCCESSFUL_RESULT(execute(
+          *mock_command_factory->constructCreateDomain(domain2_id, role)));
+      auto dom = sql_query->getDomain(domain2_id);
+      ASSERT_TRUE(dom);
+      ASSERT_EQ(dom.get()->domainId(), domain2_id);
+    }
+
+    /**
+     * @given command and a limit of domains, which is reached
+     * @when trying to create one more domain
+     * @then domain is not created
+     */
+    TEST_F(CreateDomain, DomainsCountOverLimit) {
+      addAllPermsWithoutRoot();
+      setSetting(kMaxDomainsKey, "1");
+      auto cmd_result = execute(
+          *mock_command_factory->constructCreateDomain(domain2_id, role));
+      ASSERT_FALSE(sql_query->getDomain(domain2_id));
+
+      std::vector<std::string> query_args{domain2_id, role};
+      CHECK_ERROR_CODE_AND_MESSAGE(cmd_result, 5, query_args);
+    }
+
+    /**
+     * @given command, root permission and a limit of domains, which is reached
+     * @when trying to create one more domain
+     * @then domain is created
+     */
+    TEST_F(CreateDomain, DomainsCountOverLimitWithRoot) {
+      addOnePerm(shared_model::interface::permissions::Role::kRoot);
+      setSetting(kMaxDomainsKey, "1");
+      CHECK_SUCCESSFUL_RESULT(execute(
+          *mock_command_factory->constructCreateDomain(domain2_id, role)));
+      ASSERT_TRUE(sql_query->getDomain(domain2_id));
+    }
+
+    class CreateAccount : public CommandExecutorTest {
+     public:
+      void SetUp() override {
+        CommandExecutorTest::SetUp();
+        createDefaultRole();
+        createDefaultDomain();
+        createDefaultAccount();
+      }
+
+      const shared_model::interface::types::AccountNameType account2_name =
+          "id2";
+      const shared_model::interface::types::AccountIdType account2_id =
+          account2_name + "@" + domain_id;
+    };
+
+    /**
+     * @given command and a limit of accounts per domain
+     * @when trying to create the last account allowed in the domain
+     * @then account is created
+     */
+    TEST_F(CreateAccount, AccountsCountAtLimit) {
+      addAllPermsWithoutRoot();
+      setSetting(kMaxAccountsPerDomainKey, "2");
+      CHECK_SUCCESSFUL_RESULT(
+          execute(*mock_command_factory->constructCreateAccount(
+              account2_name, domain_id, kPublicKey2)));
+      ASSERT_TRUE(sql_query->getAccount(account2_id));
+    }
+
+    /**
+     * @given command and a limit of accounts per domain, which is reached
+     * @when trying to create one more account in the domain
+     * @then account is not created
+     */
+    TEST_F(CreateAccount, AccountsCountOverLimit) {
+      addAllPermsWithoutRoot();
+      setSetting(kMaxAccountsPerDomainKey, "1");
+      auto cmd_result = execute(*mock_command_factory->constructCreateAccount(
+          account2_name, domain_id, kPublicKey2));
+      ASSERT_FALSE(sql_query->getAccount(account2_id));
+
+      std::vector<std::string> query_args{account2_id, domain_id};
+      CHECK_ERROR_CODE_AND_MESSAGE(cmd_result, 5, query_args);
+    }
+
+    /**
+     * @given command, root permission and a limit of accounts per domain,
+     * which is reached
+     * @when trying to create one more account in the domain
+     * @then account is created
+     */
+    TEST_F(CreateAccount, AccountsCountOverLimitWithRoot) {
+      addAllPerms();
+      setSetting(kMaxAccountsPerDomainKey, "1");
+      CHECK_SUCCESSFUL_RESULT(
+          execute(*mock_command_factory->constructCreateAccount(
+              account2_name, domain_id, kPublicKey2)));
+      ASSERT_TRUE(sql_query->getAccount(account2_id));
+    }
+
     class DetachRole : public CommandExecutorTest {
      public:
       void SetUp() override {
@@ -81,6 +81,18 @@ namespace iroha {
       auto settings = std::move(val(result).value().value);
       ASSERT_EQ(settings->max_description_size,
                 shared_model::validation::kDefaultDescriptionSize);
+      ASSERT_EQ(settings->max_signatures_per_transaction,
+                shared_model::validation::kDefaultMaxSignaturesPerTransaction);
+      ASSERT_EQ(settings->max_commands_per_transaction,
+                shared_model::validation::kDefaultMaxCommandsPerTransaction);
+      ASSERT_EQ(settings->max_transaction_size,
+                shared_model::validation::kDefaultMaxTransactionSize);
+      ASSERT_EQ(settings->max_domains,
+                shared_model::validation::kDefaultMaxDomains);
+      ASSERT_EQ(settings->max_accounts_per_domain,
+                shared_model::validation::kDefaultMaxAccountsPerDomain);
+      ASSERT_EQ(settings->max_assets_per_domain,
+                shared_model::validation::kDefaultMaxAssetsPerDomain);
     }
 
     /**
@@ -114,5 +126,80 @@ namespace iroha {
       ASSERT_EQ(settings->max_description_size, 255);
     }
 
+    /**
+     * @given settings
+     * @when the maximum number of signatures per transaction is set
+     * @then settings return custom value
+     */
+    TEST_F(SettingsTest, ValidMaxSignaturesPerTransaction) {
+      std::string value = "5";
+      execute(*mock_command_factory->constructSetSettingValue(
+                  iroha::ametsuchi::kMaxSignaturesPerTransactionKey, value),
+              true);
+
+      auto result = setting_query->get();
+      IROHA_ASSERT_RESULT_VALUE(result);
+      auto settings = std::move(val(result).value().value);
+      ASSERT_EQ(settings->max_signatures_per_transaction, 5);
+    }
+
+    /**
+     * @given settings
+     * @when the maximum number of commands per transaction is set
+     * @then settings return custom value
+     */
+    TEST_F(SettingsTest, ValidMaxCommandsPerTransaction) {
+      std::string value = "50";
+      execute(*mock_command_factory->constructSetSettingValue(
+                  iroha::ametsuchi::kMaxCommandsPerTransactionKey, value),
+              true);
+
+      auto result = setting_query->get();
+      IROHA_ASSERT_RESULT_VALUE(result);
+      auto settings = std::move(val(result).value().value);
+      ASSERT_EQ(settings->max_commands_per_transaction, 50);
+    }
+
+    /**
+     * @given settings
+     * @when the maximum serialized transaction size is set
+     * @then settings return custom value
+     */
+    TEST_F(SettingsTest, ValidMaxTransactionSize) {
+      std::string value = "1048576";
+      execute(*mock_command_factory->constructSetSettingValue(
+                  iroha::ametsuchi::kMaxTransactionSizeKey, value),
+              true);
+
+      auto result = setting_query->get();
+      IROHA_ASSERT_RESULT_VALUE(result);
+      auto settings = std::move(val(result).value().value);
+      ASSERT_EQ(settings->max_transaction_size, 1048576);
+    }
+
+    /**
+     * @given settings
+     * @when the maximum numbers of domains, accounts and assets are set
+     * @then settings return custom values
+     */
+    TEST_F(SettingsTest, ValidEntityLimits) {
+      execute(*mock_command_factory->constructSetSettingValue(
+                  iroha::ametsuchi::kMaxDomainsKey, "10"),
+              true);
+      execute(*mock_command_factory->constructSetSettingValue(
+                  iroha::ametsuchi::kMaxAccountsPerDomainKey, "100"),
+              true);
+      execute(*mock_command_factory->constructSetSettingValue(
+                  iroha::ametsuchi::kMaxAssetsPerDomainKey, "1000"),
+              true);
+
+      auto result = setting_query->get();
+      IROHA_ASSERT_RESULT_VALUE(result);
+      auto settings = std::move(val(result).value().value);
+      ASSERT_EQ(settings->max_domains, 10);
+      ASSERT_EQ(settings->max_accounts_per_domain, 100);
+      ASSERT_EQ(settings->max_assets_per_domain, 1000);
+    }
+
   }  // namespace ametsuchi
 }  // namespace iroha
@@ -6,6 +6,7 @@
 #include <gtest/gtest.h>
 #include <boost/format.hpp>
 
+#include <grpc++/grpc++.h>
 #include "endpoint.grpc.pb.h"  // any gRPC service is required for test
 #include "framework/test_logger.hpp"
 #include "main/server_runner.hpp"
@@ -62,3 +63,47 @@ TEST(ServerRunnerTest, SamePortWithReuse) {
   port = boost::apply_visitor(port_visitor, result);
   ASSERT_NE(0, port);
 }
+
+/**
+ * Sends a query of approximately the given size to the server on the given
+ * port
+ * @return status of the call
+ */
+grpc::Status sendQueryOfSize(int port, size_t size) {
+  auto stub = iroha::protocol::QueryService_v1::NewStub(grpc::CreateChannel(
+      "127.0.0.1:" + std::to_string(port), grpc::InsecureChannelCredentials()));
+  iroha::protocol::Query query;
+  query.mutable_payload()->mutable_meta()->set_creator_account_id(
+      std::string(size, 'a'));
+  iroha::protocol::QueryResponse response;
+  grpc::ClientContext context;
+  return stub->Find(&context, query, &response);
+}
+
+/**
+ * @given a running ServerRunner with a custom maximum received message size
+ * @when a message under the limit is sent
+ * @then the message reaches the service
+ * @when a message over the limit is sent
+ * @then the message is rejected before reaching the service
+ */
+TEST(ServerRunnerTest, MaxReceiveMessageSize) {
+  const int max_receive_message_size = 1024;
+  ServerRunner runner((address % 0).str(),
+                      getTestLogger("ServerRunner"),
+                      true,
+                      boost::none,
+                      max_receive_message_size);
+  auto query_service =
+      std::make_shared<iroha::protocol::QueryService_v1::Service>();
+  auto result = runner.append(query_service).run();
+  auto port = boost::apply_visitor(port_visitor, result);
+  ASSERT_NE(0, port);
+
+  // the service has no implementation, so a delivered query is answered with
+  // UNIMPLEMENTED status
+  ASSERT_EQ(grpc::StatusCode::UNIMPLEMENTED,
+            sendQueryOfSize(port, max_receive_message_size / 2).error_code());
+  ASSERT_EQ(grpc::StatusCode::RESOURCE_EXHAUSTED,
+            sendQueryOfSize(port, max_receive_message_size).error_code());
+}
@@ -8,10 +8,12 @@
 #include <gmock/gmock.h>
 #include <boost/range/adaptor/transformed.hpp>
 #include <boost/range/irange.hpp>
+#include "backend/protobuf/transaction.hpp"
 #include "framework/batch_helper.hpp"
 #include "framework/result_fixture.hpp"
 #include "module/irohad/common/validators_config.hpp"
 #include "module/shared_model/cryptography/crypto_defaults.hpp"
+#include "validators/settings.hpp"
 
 using namespace shared_model;
 using ::testing::_;
@@ -126,3 +128,39 @@ TEST_F(TransactionSequenceTestFixture, CreateBatches) {
   EXPECT_EQ(total_transactions,
             batches_number * txs_in_batch + single_transactions);
 }
+
+/**
+ * @given transaction with more signatures than allowed, all of them malformed
+ * @when create transaction sequence
+ * @then TransactionSequence is not created
+ * @and the error reports the signatures count without checking any signature
+ */
+TEST_F(TransactionSequenceTestFixture, OverSignedTransactionIsNotVerified) {
+  const auto signatures_count =
+      validation::kDefaultMaxSignaturesPerTransaction + 1;
+  auto transport = framework::batch::prepareTransactionBuilder("account@domain")
+                       .build()
+                       .getTransport();
+  for (auto i : boost::irange<size_t>(0, signatures_count)) {
+    auto signature = transport.add_signatures();
+    signature->set_public_key(std::to_string(i));
+    signature->set_signature(std::to_string(i));
+  }
+  std::shared_ptr<interface::Transaction> tx =
+      std::make_shared<proto::Transaction>(std::move(transport));
+
+  auto tx_sequence =
+      interface::TransactionSequenceFactory::createTransactionSequence(
+          std::vector<decltype(tx)>{tx},
+          txs_collection_validator,
+          field_validator);
+
+  auto error = framework::expected::err(tx_sequence);
+  ASSERT_TRUE(error);
+  EXPECT_THAT(error->error,
+              ::testing::HasSubstr("Contains "
+                                   + std::to_string(signatures_count)
+                                   + " signatures"));
+  EXPECT_THAT(error->error,
+              ::testing::Not(::testing::HasSubstr("Signature #")));
+}
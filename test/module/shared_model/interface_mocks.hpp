@@ -70,6 +70,8 @@ struct MockTransaction : public shared_model::interface::Transaction {
   MOCK_CONST_METHOD0(
       batchMeta,
       std::optional<std::shared_ptr<shared_model::interface::BatchMeta>>());
+  MOCK_CONST_METHOD0(rawSignaturesCount, size_t());
+  MOCK_CONST_METHOD0(serializedSize, size_t());
 };
 
 /**
@@ -13,6 +13,8 @@
 #include "builders/protobuf/transaction.hpp"
 #include "module/irohad/common/validators_config.hpp"
 #include "module/shared_model/builders/protobuf/test_transaction_builder.hpp"
+#include "validators/default_validator.hpp"
+#include "validators/settings.hpp"
 #include "validators/validation_error_output.hpp"
 
 using namespace shared_model;
@@ -20,7 +22,8 @@ using namespace shared_model;
 class TransactionValidatorTest : public ValidatorsTest {
  public:
   TransactionValidatorTest()
-      : transaction_validator(iroha::test::kTestsValidatorsConfig) {}
+      : transaction_validator(iroha::test::kTestsValidatorsConfig),
+        field_validator(iroha::test::kTestsValidatorsConfig) {}
 
   auto getCountIgnoredFields() {
     return ignored_fields_.size();
@@ -38,8 +41,57 @@ class TransactionValidatorTest : public ValidatorsTest {
                   .getTransport();
     return tx;
   }
+
+  /**
+   * Creates a transaction with a valid command and the given number of
+   * signatures made by distinct public keys
+   */
+  iroha::protocol::Transaction generateTransactionWithSignatures(
+      size_t signatures_count) {
+    TestTransactionBuilder builder;
+    auto tx = builder.creatorAccountId("admin@test")
+                  .createdTime(created_time)
+                  .quorum(1)
+                  .createDomain("test", "test")
+                  .build()
+                  .getTransport();
+    for (auto i : boost::irange<size_t>(0, signatures_count)) {
+      auto signature = tx.add_signatures();
+      signature->set_public_key(std::to_string(i));
+      signature->set_signature(std::to_string(i));
+    }
+    return tx;
+  }
+
+  /**
+   * Creates transaction validator which allows at most the given number of
+   * commands per transaction
+   */
+  shared_model::validation::DefaultUnsignedTransactionValidator
+  makeValidatorWithMaxCommands(size_t max_commands) {
+    auto settings = shared_model::validation::getDefaultSettings();
+    settings->max_commands_per_transaction = max_commands;
+    return shared_model::validation::DefaultUnsignedTransactionValidator(
+        std::make_shared<shared_model::validation::ValidatorsConfig>(
+            iroha::test::getTestsMaxBatchSize(), std::move(settings)));
+  }
+
+  /**
+   * Creates transaction validator which allows transactions of at most the
+   * given serialized size
+   */
+  shared_model::validation::DefaultUnsignedTransactionValidator
+  makeValidatorWithMaxTransactionSize(size_t max_transaction_size) {
+    auto settings = shared_model::validation::getDefaultSettings();
+    settings->max_transaction_size = max_transaction_size;
+    return shared_model::validation::DefaultUnsignedTransactionValidator(
+        std::make_shared<shared_model::validation::ValidatorsConfig>(
+            iroha::test::getTestsMaxBatchSize(), std::move(settings)));
+  }
+
   shared_model::validation::DefaultUnsignedTransactionValidator
       transaction_validator;
+  shared_model::validation::FieldValidator field_validator;
 };
 
 /**
@@ -174,3 +226,220 @@ TEST_F(TransactionValidatorTest, BatchValidTest) {
   ASSERT_EQ(tx.payload().batch().type(),
             static_cast<int>(interface::types::BatchType::ATOMIC));
 }
+
+/**
+ * @given transaction with the maximum allowed number of signatures
+ * @when signatures count validation is invoked
+ * @then answer has no errors
+ */
+TEST_F(TransactionValidatorTest, SignaturesCountAtLimit) {
+  auto tx = generateTransactionWithSignatures(
+      shared_model::validation::kDefaultMaxSignaturesPerTransaction);
+  auto result = proto::Transaction(iroha::protocol::Transaction(tx));
+
+  ASSERT_EQ(field_validator.validateSignaturesCount(result), std::nullopt);
+}
+
+/**
+ * @given transaction with one signature more than allowed
+ * @when signatures count validation is invoked
+ * @then answer has an error about the signatures count
+ */
+TEST_F(TransactionValidatorTest, SignaturesCountOverLimit) {
+  const auto signatures_count =
+      shared_model::validation::kDefaultMaxSignaturesPerTransaction + 1;
+  auto tx = generateTransactionWithSignatures(signatures_count);
+  auto result = proto::Transaction(iroha::protocol::Transaction(tx));
+
+  auto error = field_validator.validateSignaturesCount(result);
+  ASSERT_TRUE(error);
+  ASSERT_THAT(error->toString(),
+              ::testing::HasSubstr("Contains "
+                                   + std::to_string(signatures_count)
+                                   + " signatures"));
+}
+
+/**
+ * @given transaction with two signatures made by the same public key
+ * @when signatures count validation is invoked
+ * @then answer has an error about the repeated public key
+ */
+TEST_F(TransactionValidatorTest, SignaturesWithSamePublicKey) {
+  auto tx = generateTransactionWithSignatures(2);
+  tx.mutable_signatures(1)->set_public_key(tx.signatures(0).public_key());
+  auto result = proto::Transaction(iroha::protocol::Transaction(tx));
+
+  auto error = field_validator.validateSignaturesCount(result);
+  ASSERT_TRUE(error);
+  ASSERT_THAT(error->toString(),
+              ::testing::HasSubstr(
+                  "Contains several signatures with the same public key."));
+}
+
+/**
+ * @given transaction with two signatures made by the same public key written
+ * in different letter case
+ * @when signatures count validation is invoked
+ * @then answer has an error about the repeated public key
+ */
+TEST_F(TransactionValidatorTest, SignaturesWithSamePublicKeyInDifferentCase) {
+  auto tx = generateTransactionWithSignatures(2);
+  tx.mutable_signatures(0)->set_public_key("abcdef");
+  tx.mutable_signatures(1)->set_public_key("ABCDEF");
+  auto result = proto::Transaction(iroha::protocol::Transaction(tx));
+
+  auto error = field_validator.validateSignaturesCount(result);
+  ASSERT_TRUE(error);
+  ASSERT_THAT(error->toString(),
+              ::testing::HasSubstr(
+                  "Contains several signatures with the same public key."));
+}
+
+/**
+ * @given transaction with two signatures made by the same public key
+ * @when stateless transaction validation, which is also used for transactions
+ * of blocks, is invoked
+ * @then answer has no errors, since repeated keys are rejected only when a
+ * transaction is received, and already committed blocks must stay valid
+ */
+TEST_F(TransactionValidatorTest, SignaturesWithSamePublicKeyInBlock) {
+  auto tx = generateTransactionWithSignatures(2);
+  tx.mutable_signatures(1)->set_public_key(tx.signatures(0).public_key());
+  auto result = proto::Transaction(iroha::protocol::Transaction(tx));
+
+  ASSERT_EQ(transaction_validator.validate(result), std::nullopt);
+}
+
+/**
+ * @given transaction with more signatures than allowed, all made by the same
+ * public key
+ * @when signatures count validation is invoked
+ * @then answer has an error about the signatures count
+ */
+TEST_F(TransactionValidatorTest, SignaturesOverLimitWithSamePublicKey) {
+  const auto signatures_count =
+      shared_model::validation::kDefaultMaxSignaturesPerTransaction + 1;
+  auto tx = generateTransactionWithSignatures(signatures_count);
+  for (auto &signature : *tx.mutable_signatures()) {
+    signature.set_public_key("0");
+  }
+  auto result = proto::Transaction(iroha::protocol::Transaction(tx));
+
+  auto error = field_validator.validateSignaturesCount(result);
+  ASSERT_TRUE(error);
+  ASSERT_THAT(error->toString(),
+              ::testing::HasSubstr("Contains "
+                                   + std::to_string(signatures_count)
+                                   + " signatures"));
+}
+
+/**
+ * @given transaction with more signatures than allowed
+ * @when signed transaction validation is invoked
+ * @then answer has an error about the signatures count
+ * @and none of the signatures is checked, so there are no per-signature errors
+ * although every signature is malformed
+ */
+TEST_F(TransactionValidatorTest, SignaturesOverLimitAreNotVerified) {
+  const auto signatures_count =
+      shared_model::validation::kDefaultMaxSignaturesPerTransaction + 1;
+  auto tx = generateTransactionWithSignatures(signatures_count);
+  auto result = proto::Transaction(iroha::protocol::Transaction(tx));
+  shared_model::validation::DefaultSignedTransactionValidator
+      signed_transaction_validator(iroha::test::kTestsValidatorsConfig);
+
+  auto error = signed_transaction_validator.validate(result);
+  ASSERT_TRUE(error);
+  ASSERT_THAT(error->toString(),
+              ::testing::HasSubstr("Contains "
+                                   + std::to_string(signatures_count)
+                                   + " signatures"));
+  ASSERT_THAT(error->toString(),
+              ::testing::Not(::testing::HasSubstr("Signature #")));
+}
+
+/**
+ * @given transaction validator with a custom commands limit
+ * @when transaction with the maximum allowed number of commands is validated
+ * @then answer has no errors
+ */
+TEST_F(TransactionValidatorTest, CommandsCountAtLimit) {
+  auto validator = makeValidatorWithMaxCommands(2);
+  auto tx = TestTransactionBuilder()
+                .creatorAccountId("admin@test")
+                .createdTime(created_time)
+                .quorum(1)
+                .createDomain("first", "test")
+                .createDomain("second", "test")
+                .build();
+
+  ASSERT_EQ(validator.validate(tx), std::nullopt);
+}
+
+/**
+ * @given transaction validator with a custom commands limit
+ * @when transaction with one command more than allowed is validated
+ * @then answer has an error about the commands count
+ * @and the commands themselves are not validated
+ */
+TEST_F(TransactionValidatorTest, CommandsCountOverLimit) {
+  auto validator = makeValidatorWithMaxCommands(2);
+  auto tx = TestTransactionBuilder()
+                .creatorAccountId("admin@test")
+                .createdTime(created_time)
+                .quorum(1)
+                .createDomain("first", "test")
+                .createDomain("second", "test")
+                .createDomain("", "")
+                .build();
+
+  auto error = validator.validate(tx);
+  ASSERT_TRUE(error);
+  ASSERT_THAT(error->toString(),
+              ::testing::HasSubstr("Contains 3 commands, maximum is '2'."));
+  ASSERT_THAT(error->toString(),
+              ::testing::Not(::testing::HasSubstr("Command #")));
+}
+
+/**
+ * @given transaction validator with a custom transaction size limit
+ * @when transaction of exactly the allowed size is validated
+ * @then answer has no errors
+ */
+TEST_F(TransactionValidatorTest, TransactionSizeAtLimit) {
+  auto tx = TestTransactionBuilder()
+                .creatorAccountId("admin@test")
+                .createdTime(created_time)
+                .quorum(1)
+                .createDomain("first", "test")
+                .build();
+  auto validator = makeValidatorWithMaxTransactionSize(tx.serializedSize());
+
+  ASSERT_EQ(validator.validate(tx), std::nullopt);
+}
+
+/**
+ * @given transaction validator with a custom transaction size limit
+ * @when transaction one byte bigger than allowed is validated
+ * @then answer has an error about the transaction size
+ * @and the commands themselves are not validated
+ */
+TEST_F(TransactionValidatorTest, TransactionSizeOverLimit) {
+  auto tx = TestTransactionBuilder()
+                .creatorAccountId("admin@test")
+                .createdTime(created_time)
+                .quorum(1)
+                .createDomain("", "")
+                .build();
+  const auto max_transaction_size = tx.serializedSize() - 1;
+  auto validator = makeValidatorWithMaxTransactionSize(max_transaction_size);
+
+  auto error = validator.validate(tx);
+  ASSERT_TRUE(error);
+  ASSERT_THAT(error->toString(),
+              ::testing::HasSubstr("Transaction exceeds "
+                                   + std::to_string(max_transaction_size)
+                                   + " bytes"));
+  ASSERT_THAT(error->toString(),
+              ::testing::Not(::testing::HasSubstr("Command #")));
+}